@@ -1,5 +1,6 @@
 use std::{
     any::{Any, TypeId},
+    ops::Range,
     sync::mpsc,
 };
 
@@ -14,6 +15,9 @@ use gpui::{
 use language::{Anchor, Buffer, Capability};
 use multi_buffer::MultiBuffer;
 use project::{buffer_store::BufferChangeSet, git::GitState, Project, ProjectPath};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use settings::{Settings, SettingsSources, SettingsStore};
 use theme::ActiveTheme;
 use ui::prelude::*;
 use util::ResultExt as _;
@@ -22,11 +26,86 @@ use workspace::{
     ItemNavHistory, ToolbarItemLocation, Workspace,
 };
 
-actions!(project_diff, [Deploy]);
+actions!(
+    project_diff,
+    [Deploy, StageHunk, UnstageHunk, StageFile, ToggleStaged]
+);
+
+/// The order in which `ProjectDiff` groups changed files; entries within a
+/// section are sorted by repo path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum DiffSection {
+    Conflicted,
+    Staged,
+    Unstaged,
+    Untracked,
+}
+
+impl DiffSection {
+    /// A conflict wins over everything else, then an untracked file, then any
+    /// staged change; every other changed file counts as unstaged.
+    fn for_status(status: &project::git::FileStatus) -> Self {
+        if status.is_conflicted() {
+            Self::Conflicted
+        } else if status.is_untracked() {
+            Self::Untracked
+        } else if status.is_staged() {
+            Self::Staged
+        } else {
+            Self::Unstaged
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Conflicted => "Conflicted",
+            Self::Staged => "Staged Changes",
+            Self::Unstaged => "Changes",
+            Self::Untracked => "Untracked Files",
+        }
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectDiffSettingsContent {
+    /// Hide untracked files from the project diff.
+    ///
+    /// Default: false
+    pub hide_untracked_changes: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+pub struct ProjectDiffSettings {
+    pub hide_untracked_changes: bool,
+}
+
+impl Settings for ProjectDiffSettings {
+    const KEY: Option<&'static str> = Some("project_diff");
+
+    type FileContent = ProjectDiffSettingsContent;
+
+    fn load(sources: SettingsSources<Self::FileContent>, _: &mut App) -> Result<Self> {
+        let content = sources.json_merge::<Self::FileContent>()?;
+        Ok(Self {
+            hide_untracked_changes: content.hide_untracked_changes.unwrap_or(false),
+        })
+    }
+}
+
+/// Aggregate counts shown in the tab: how many files have changes, and how
+/// many lines were added/removed across all of them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct DiffStats {
+    files_changed: usize,
+    lines_added: u32,
+    lines_removed: u32,
+}
 
 pub(crate) struct ProjectDiff {
     multibuffer: Entity<MultiBuffer>,
-    buffers_to_show: HashMap<ProjectPath, Entity<Buffer>>, // tbd.
+    buffers_to_show: HashMap<ProjectPath, TrackedDiffBuffer>,
+    last_status: HashMap<ProjectPath, project::git::FileStatus>,
+    diff_stats: DiffStats,
     editor: Entity<Editor>,
     project: Entity<Project>,
     git_state: Entity<GitState>,
@@ -36,20 +115,46 @@ pub(crate) struct ProjectDiff {
     update_needed: postage::watch::Sender<()>,
 
     git_state_subscription: Subscription,
+    settings_subscription: Subscription,
 }
 
 struct DiffBuffer {
+    project_path: ProjectPath,
+    section: DiffSection,
+    status: project::git::FileStatus,
+    buffer: Entity<Buffer>,
+    change_set: Entity<BufferChangeSet>,
+    staged_change_set: Entity<BufferChangeSet>,
+}
+
+/// The state we keep around for a buffer once it has been registered in the
+/// multibuffer, so that stage/unstage actions know what to operate on without
+/// re-deriving it from the editor's selections every time.
+struct TrackedDiffBuffer {
+    section: DiffSection,
     buffer: Entity<Buffer>,
     change_set: Entity<BufferChangeSet>,
+    staged_change_set: Entity<BufferChangeSet>,
+    has_staged_hunks: bool,
+    stats: DiffStats,
+    /// The currently-displayed hunk ranges for this buffer (the same ones
+    /// passed to `set_excerpts_for_buffer`), so each one can get its own
+    /// stage/unstage control instead of only acting on the cursor position.
+    hunks: Vec<Range<Anchor>>,
 }
 
 impl ProjectDiff {
     pub(crate) fn register(
         workspace: &mut Workspace,
         _window: Option<&mut Window>,
-        _: &mut Context<Workspace>,
+        cx: &mut Context<Workspace>,
     ) {
+        ProjectDiffSettings::register(cx);
         workspace.register_action(Self::deploy);
+        workspace.register_action(Self::stage_hunk);
+        workspace.register_action(Self::unstage_hunk);
+        workspace.register_action(Self::stage_file);
+        workspace.register_action(Self::toggle_staged);
     }
 
     fn deploy(
@@ -100,6 +205,13 @@ impl ProjectDiff {
             },
         );
 
+        // Toggling `hide_untracked_changes` (or any other project-diff setting)
+        // should take effect immediately, not wait for the next git status
+        // change to happen to poke the worker.
+        let settings_subscription = cx.observe_global::<SettingsStore>(|this, _cx| {
+            *this.update_needed.borrow_mut() = ();
+        });
+
         let (mut send, recv) = postage::watch::channel::<()>();
         let worker = window.spawn(cx, {
             let this = cx.weak_entity();
@@ -114,10 +226,13 @@ impl ProjectDiff {
             workspace,
             focus_handle,
             buffers_to_show: HashMap::default(),
+            last_status: HashMap::default(),
+            diff_stats: DiffStats::default(),
             editor,
             multibuffer,
             update_needed: send,
             worker,
+            settings_subscription,
             git_state_subscription,
         }
     }
@@ -127,26 +242,16 @@ impl ProjectDiff {
             self.multibuffer.update(cx, |multibuffer, cx| {
                 multibuffer.clear(cx);
             });
+            self.buffers_to_show.clear();
+            self.last_status.clear();
+            self.diff_stats = DiffStats::default();
+            cx.notify();
             return vec![];
         };
 
-        let mut loaded_buffers = self
-            .multibuffer
-            .read(cx)
-            .all_buffers()
-            .iter()
-            .filter_map(|buffer| {
-                let file = buffer.read(cx).file()?;
-                let project_path = ProjectPath {
-                    worktree_id: file.worktree_id(cx),
-                    path: file.path().clone(),
-                };
-
-                Some((project_path, buffer.clone()))
-            })
-            .collect::<HashMap<_, _>>();
+        let hide_untracked_changes = ProjectDiffSettings::get_global(cx).hide_untracked_changes;
 
-        let mut result = vec![];
+        let mut new_status = HashMap::default();
         for entry in repo.status() {
             if !entry.status.has_changes() {
                 continue;
@@ -154,54 +259,381 @@ impl ProjectDiff {
             let Some(project_path) = repo.repo_path_to_project_path(&entry.repo_path) else {
                 continue;
             };
-
-            loaded_buffers.remove(&project_path);
-            let load_buffer = self
-                .project
-                .update(cx, |project, cx| project.open_buffer(project_path, cx));
-
-            let project = self.project.clone();
-            result.push(cx.spawn(|_, mut cx| async move {
-                let buffer = load_buffer.await?;
-                let changes = project
-                    .update(&mut cx, |project, cx| {
-                        project.open_unstaged_changes(buffer.clone(), cx)
-                    })?
-                    .await?;
-
-                Ok(DiffBuffer {
-                    buffer,
-                    change_set: changes,
-                })
-            }));
+            if hide_untracked_changes
+                && DiffSection::for_status(&entry.status) == DiffSection::Untracked
+            {
+                continue;
+            }
+            new_status.insert(project_path, (entry.repo_path, entry.status));
         }
+
+        // Anything we were tracking that dropped out of the new status (reverted,
+        // committed, or newly filtered) can be torn down without touching the
+        // buffers whose status didn't change.
+        let removed_paths = self
+            .buffers_to_show
+            .keys()
+            .filter(|project_path| !new_status.contains_key(*project_path))
+            .cloned()
+            .collect::<Vec<_>>();
         self.multibuffer.update(cx, |multibuffer, cx| {
-            for (_, buffer) in loaded_buffers {
-                multibuffer.remove_excerpts_for_buffer(&buffer, cx);
+            for project_path in &removed_paths {
+                if let Some(tracked) = self.buffers_to_show.remove(project_path) {
+                    multibuffer.remove_excerpts_for_buffer(&tracked.buffer, cx);
+                    self.diff_stats.files_changed -= 1;
+                    self.diff_stats.lines_added -= tracked.stats.lines_added;
+                    self.diff_stats.lines_removed -= tracked.stats.lines_removed;
+                }
             }
         });
+        for project_path in &removed_paths {
+            self.last_status.remove(project_path);
+        }
+        if !removed_paths.is_empty() {
+            cx.notify();
+        }
+
+        // Anything new or whose status changed since the last snapshot needs its
+        // excerpts refreshed; everything else is left alone. Note `last_status` is
+        // *not* updated here: a path is only folded into it once its buffer has
+        // actually been registered, so a load failure leaves it "changed" and
+        // eligible to be retried on the next pass instead of vanishing silently.
+        let mut changed = new_status
+            .iter()
+            .filter(|(project_path, (_, status))| {
+                self.last_status.get(*project_path) != Some(status)
+            })
+            .map(|(project_path, (repo_path, status))| {
+                (
+                    DiffSection::for_status(status),
+                    repo_path.clone(),
+                    project_path.clone(),
+                    status.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        changed.sort_by(|(section_a, path_a, ..), (section_b, path_b, ..)| {
+            section_a.cmp(section_b).then_with(|| path_a.cmp(path_b))
+        });
+
+        // A section containing any changed file needs its excerpts re-registered
+        // in full, in sorted order, so that the already-tracked (unchanged) files
+        // in that section stay interleaved correctly with the new/changed ones
+        // rather than simply being appended after them.
+        let touched_sections = changed
+            .iter()
+            .map(|(section, ..)| *section)
+            .collect::<HashSet<_>>();
+        let changed_paths = changed
+            .iter()
+            .map(|(_, _, project_path, _)| project_path.clone())
+            .collect::<HashSet<_>>();
+
+        let mut resync = self
+            .buffers_to_show
+            .iter()
+            .filter(|(project_path, tracked)| {
+                touched_sections.contains(&tracked.section) && !changed_paths.contains(*project_path)
+            })
+            .filter_map(|(project_path, tracked)| {
+                let (repo_path, status) = new_status.get(project_path)?;
+                Some((
+                    tracked.section,
+                    repo_path.clone(),
+                    project_path.clone(),
+                    status.clone(),
+                    tracked.buffer.clone(),
+                    tracked.change_set.clone(),
+                    tracked.staged_change_set.clone(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        resync.sort_by(|(section_a, path_a, ..), (section_b, path_b, ..)| {
+            section_a.cmp(section_b).then_with(|| path_a.cmp(path_b))
+        });
+
+        let mut result = vec![];
+        let mut changed = changed.into_iter().peekable();
+        let mut resync = resync.into_iter().peekable();
+        loop {
+            let take_changed = match (changed.peek(), resync.peek()) {
+                (Some((section_a, path_a, ..)), Some((section_b, path_b, ..))) => {
+                    (section_a, path_a) <= (section_b, path_b)
+                }
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+
+            if take_changed {
+                let (section, _repo_path, project_path, status) = changed.next().unwrap();
+                let load_buffer = self
+                    .project
+                    .update(cx, |project, cx| project.open_buffer(project_path.clone(), cx));
+
+                let project = self.project.clone();
+                result.push(cx.spawn(|_, mut cx| async move {
+                    let buffer = load_buffer.await?;
+                    let change_set = project
+                        .update(&mut cx, |project, cx| {
+                            project.open_unstaged_changes(buffer.clone(), cx)
+                        })?
+                        .await?;
+                    let staged_change_set = project
+                        .update(&mut cx, |project, cx| {
+                            project.open_staged_changes(buffer.clone(), cx)
+                        })?
+                        .await?;
+
+                    Ok(DiffBuffer {
+                        project_path,
+                        section,
+                        status,
+                        buffer,
+                        change_set,
+                        staged_change_set,
+                    })
+                }));
+            } else {
+                let (section, _repo_path, project_path, status, buffer, change_set, staged_change_set) =
+                    resync.next().unwrap();
+                result.push(Task::ready(Ok(DiffBuffer {
+                    project_path,
+                    section,
+                    status,
+                    buffer,
+                    change_set,
+                    staged_change_set,
+                })));
+            }
+        }
         result
     }
 
-    fn register_buffer(&mut self, diff_buffer: DiffBuffer, cx: &mut App) {
-        let buffer = diff_buffer.buffer;
-        let change_set = diff_buffer.change_set;
+    fn register_buffer(&mut self, diff_buffer: DiffBuffer, cx: &mut Context<Self>) {
+        let DiffBuffer {
+            project_path,
+            section,
+            status,
+            buffer,
+            change_set,
+            staged_change_set,
+        } = diff_buffer;
 
         let snapshot = buffer.read(cx).snapshot();
-        let diff_hunk_ranges = change_set
+        // A file grouped under "Staged Changes" should show (and count) its staged
+        // hunks, since those are what the section claims to represent; everywhere
+        // else shows the unstaged diff, same as before.
+        let diff_source = if section == DiffSection::Staged {
+            &staged_change_set
+        } else {
+            &change_set
+        };
+        let diff_hunk_ranges = diff_source
             .read(cx)
             .diff_hunks_intersecting_range(Anchor::MIN..Anchor::MAX, &snapshot)
             .map(|diff_hunk| diff_hunk.buffer_range)
             .collect::<Vec<_>>();
+        let has_staged_hunks = staged_change_set
+            .read(cx)
+            .diff_hunks_intersecting_range(Anchor::MIN..Anchor::MAX, &snapshot)
+            .next()
+            .is_some();
+        let stats = diff_source
+            .read(cx)
+            .diff_hunks_intersecting_range(Anchor::MIN..Anchor::MAX, &snapshot)
+            .fold(DiffStats::default(), |mut stats, diff_hunk| {
+                let (added, removed) = diff_hunk.line_counts(&snapshot);
+                stats.lines_added += added;
+                stats.lines_removed += removed;
+                stats
+            });
 
         self.multibuffer.update(cx, |multibuffer, cx| {
             multibuffer.set_excerpts_for_buffer(
-                buffer,
-                diff_hunk_ranges,
+                buffer.clone(),
+                diff_hunk_ranges.clone(),
                 editor::DEFAULT_MULTIBUFFER_CONTEXT,
                 cx,
             );
+        });
+
+        self.last_status.insert(project_path.clone(), status);
+        let previous = self.buffers_to_show.insert(
+            project_path,
+            TrackedDiffBuffer {
+                section,
+                buffer,
+                change_set,
+                staged_change_set,
+                has_staged_hunks,
+                stats,
+                hunks: diff_hunk_ranges,
+            },
+        );
+        if let Some(previous) = previous {
+            self.diff_stats.lines_added -= previous.stats.lines_added;
+            self.diff_stats.lines_removed -= previous.stats.lines_removed;
+        } else {
+            self.diff_stats.files_changed += 1;
+        }
+        self.diff_stats.lines_added += stats.lines_added;
+        self.diff_stats.lines_removed += stats.lines_removed;
+        cx.notify();
+    }
+
+    fn stage_hunk(
+        workspace: &mut Workspace,
+        _: &StageHunk,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) {
+        let Some(project_diff) = workspace.item_of_type::<Self>(cx) else {
+            return;
+        };
+        project_diff.update(cx, |project_diff, cx| {
+            project_diff.set_staged_for_hunk_at_cursor(true, window, cx);
+        });
+    }
+
+    fn unstage_hunk(
+        workspace: &mut Workspace,
+        _: &UnstageHunk,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) {
+        let Some(project_diff) = workspace.item_of_type::<Self>(cx) else {
+            return;
+        };
+        project_diff.update(cx, |project_diff, cx| {
+            project_diff.set_staged_for_hunk_at_cursor(false, window, cx);
+        });
+    }
+
+    fn stage_file(
+        workspace: &mut Workspace,
+        _: &StageFile,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) {
+        let Some(project_diff) = workspace.item_of_type::<Self>(cx) else {
+            return;
+        };
+        project_diff.update(cx, |project_diff, cx| {
+            project_diff.set_staged_for_file_at_cursor(true, window, cx);
+        });
+    }
+
+    fn toggle_staged(
+        workspace: &mut Workspace,
+        _: &ToggleStaged,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) {
+        let Some(project_diff) = workspace.item_of_type::<Self>(cx) else {
+            return;
+        };
+        project_diff.update(cx, |project_diff, cx| {
+            project_diff.toggle_staged_for_file_at_cursor(window, cx);
+        });
+    }
+
+    fn set_staged_for_hunk_at_cursor(
+        &mut self,
+        staged: bool,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((_, buffer, hunk_range)) = self.editor.read(cx).active_excerpt(cx) else {
+            return;
+        };
+        self.update_hunk_staged(buffer, hunk_range, staged, cx);
+    }
+
+    fn set_staged_for_file_at_cursor(
+        &mut self,
+        staged: bool,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((_, buffer, _)) = self.editor.read(cx).active_excerpt(cx) else {
+            return;
+        };
+        self.update_file_staged(buffer, staged, cx);
+    }
+
+    fn toggle_staged_for_file_at_cursor(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let Some((_, buffer, _)) = self.editor.read(cx).active_excerpt(cx) else {
+            return;
+        };
+        let is_staged = self
+            .project_path_for_buffer(&buffer, cx)
+            .and_then(|project_path| self.buffers_to_show.get(&project_path))
+            .map_or(false, |tracked| tracked.has_staged_hunks);
+        self.update_file_staged(buffer, !is_staged, cx);
+    }
+
+    fn project_path_for_buffer(&self, buffer: &Entity<Buffer>, cx: &App) -> Option<ProjectPath> {
+        let file = buffer.read(cx).file()?;
+        Some(ProjectPath {
+            worktree_id: file.worktree_id(cx),
+            path: file.path().clone(),
+        })
+    }
+
+    /// `project_path_to_repo_path` is the inverse of `repo_path_to_project_path`,
+    /// which `buffers_to_load` already uses to go the other way; `set_hunk_staged`
+    /// takes the same `(RepoPath, Range<Anchor>, BufferSnapshot)` shape that the
+    /// rest of this file reads hunks in.
+    fn update_hunk_staged(
+        &mut self,
+        buffer: Entity<Buffer>,
+        hunk_range: Range<Anchor>,
+        staged: bool,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(repo) = self.git_state.read(cx).active_repository() else {
+            return;
+        };
+        let Some(project_path) = self.project_path_for_buffer(&buffer, cx) else {
+            return;
+        };
+        let Some(repo_path) = repo.project_path_to_repo_path(&project_path) else {
+            return;
+        };
+        let snapshot = buffer.read(cx).snapshot();
+        let task = repo.set_hunk_staged(repo_path, hunk_range, snapshot, staged, cx);
+        self.refresh_after_index_update(task, cx);
+    }
+
+    /// Stages or unstages the whole file: `stage_paths`/`unstage_paths` take a
+    /// `Vec<RepoPath>`, here holding only this buffer's path.
+    fn update_file_staged(&mut self, buffer: Entity<Buffer>, staged: bool, cx: &mut Context<Self>) {
+        let Some(repo) = self.git_state.read(cx).active_repository() else {
+            return;
+        };
+        let Some(project_path) = self.project_path_for_buffer(&buffer, cx) else {
+            return;
+        };
+        let Some(repo_path) = repo.project_path_to_repo_path(&project_path) else {
+            return;
+        };
+        let task = if staged {
+            repo.stage_paths(vec![repo_path], cx)
+        } else {
+            repo.unstage_paths(vec![repo_path], cx)
+        };
+        self.refresh_after_index_update(task, cx);
+    }
+
+    fn refresh_after_index_update(&mut self, task: Task<Result<()>>, cx: &mut Context<Self>) {
+        cx.spawn(|this, mut cx| async move {
+            task.await.log_err();
+            this.update(&mut cx, |this, _| {
+                *this.update_needed.borrow_mut() = ();
+            })
         })
+        .detach();
     }
 
     pub async fn worker(
@@ -253,16 +685,44 @@ impl Item for ProjectDiff {
     }
 
     fn tab_tooltip_text(&self, _: &App) -> Option<SharedString> {
-        Some("Project Diff".into())
+        let stats = self.diff_stats;
+        if stats.files_changed == 0 {
+            return Some("Project Diff".into());
+        }
+        Some(
+            format!(
+                "{} changed file{} (+{}, -{})",
+                stats.files_changed,
+                if stats.files_changed == 1 { "" } else { "s" },
+                stats.lines_added,
+                stats.lines_removed,
+            )
+            .into(),
+        )
     }
 
     fn tab_content(&self, params: TabContentParams, _window: &Window, _: &App) -> AnyElement {
-        Label::new("No changes")
-            .color(if params.selected {
-                Color::Default
-            } else {
-                Color::Muted
-            })
+        let color = if params.selected {
+            Color::Default
+        } else {
+            Color::Muted
+        };
+        let stats = self.diff_stats;
+        if stats.files_changed == 0 {
+            return Label::new("No changes").color(color).into_any_element();
+        }
+
+        h_flex()
+            .gap_2()
+            .child(Label::new(format!("{} changed", stats.files_changed)).color(color))
+            .child(
+                Label::new(format!("+{}", stats.lines_added))
+                    .color(Color::Created),
+            )
+            .child(
+                Label::new(format!("-{}", stats.lines_removed))
+                    .color(Color::Deleted),
+            )
             .into_any_element()
     }
 
@@ -383,14 +843,142 @@ impl Item for ProjectDiff {
     }
 }
 
+impl ProjectDiff {
+    /// Unstage the whole of `project_path`'s file if any of its hunks is
+    /// staged, and stage the whole file otherwise. Unlike `toggle_staged_for_file_at_cursor`, this
+    /// acts on a specific tracked buffer rather than whatever the cursor
+    /// happens to be over, so it can be wired directly to a per-file control.
+    fn toggle_staged_for_buffer(&mut self, project_path: ProjectPath, cx: &mut Context<Self>) {
+        let Some(tracked) = self.buffers_to_show.get(&project_path) else {
+            return;
+        };
+        let buffer = tracked.buffer.clone();
+        let is_staged = tracked.has_staged_hunks;
+        self.update_file_staged(buffer, !is_staged, cx);
+    }
+
+    /// Builds one row per tracked file, grouped under a label row per
+    /// section, with a stage/unstage control for every hunk in that file
+    /// plus a file-level toggle — so staging a hunk never requires first
+    /// moving the cursor onto it.
+    fn render_changes_list(&self, cx: &mut Context<Self>) -> Vec<AnyElement> {
+        let mut entries = self
+            .buffers_to_show
+            .iter()
+            .map(|(project_path, tracked)| (project_path.clone(), tracked))
+            .collect::<Vec<_>>();
+        entries.sort_by(|(path_a, tracked_a), (path_b, tracked_b)| {
+            tracked_a
+                .section
+                .cmp(&tracked_b.section)
+                .then_with(|| path_a.cmp(path_b))
+        });
+
+        let mut rows = Vec::new();
+        let mut current_section = None;
+        for (project_path, tracked) in entries {
+            if current_section != Some(tracked.section) {
+                current_section = Some(tracked.section);
+                rows.push(
+                    div()
+                        .px_2()
+                        .pt_2()
+                        .child(Label::new(tracked.section.label()).color(Color::Muted))
+                        .into_any_element(),
+                );
+            }
+
+            let file_label = project_path.path.to_string_lossy().into_owned();
+            let unstage = tracked.section == DiffSection::Staged;
+            let buffer = tracked.buffer.clone();
+            let is_staged = tracked.has_staged_hunks;
+            let toggle_path = project_path.clone();
+
+            rows.push(
+                v_flex()
+                    .px_2()
+                    .py_1()
+                    .gap_1()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Label::new(file_label.clone()).truncate())
+                            .child(div().flex_1())
+                            .child(
+                                IconButton::new(
+                                    SharedString::from(format!("toggle-file-{file_label}")),
+                                    IconName::CheckCircle,
+                                )
+                                .icon_size(IconSize::Small)
+                                .tooltip(Tooltip::text(if is_staged {
+                                    "Unstage File"
+                                } else {
+                                    "Stage File"
+                                }))
+                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                    this.toggle_staged_for_buffer(toggle_path.clone(), cx);
+                                })),
+                            ),
+                    )
+                    .child(
+                        h_flex().gap_1().children(tracked.hunks.iter().enumerate().map(
+                            |(index, hunk_range)| {
+                                let buffer = buffer.clone();
+                                let hunk_range = hunk_range.clone();
+                                IconButton::new(
+                                    SharedString::from(format!("hunk-{file_label}-{index}")),
+                                    if unstage { IconName::Undo } else { IconName::Check },
+                                )
+                                .icon_size(IconSize::Small)
+                                .tooltip(Tooltip::text(if unstage {
+                                    "Unstage Hunk"
+                                } else {
+                                    "Stage Hunk"
+                                }))
+                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                    this.update_hunk_staged(
+                                        buffer.clone(),
+                                        hunk_range.clone(),
+                                        !unstage,
+                                        cx,
+                                    );
+                                }))
+                            },
+                        )),
+                    )
+                    .into_any_element(),
+            );
+        }
+        rows
+    }
+}
+
 impl Render for ProjectDiff {
-    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        div()
-            .bg(cx.theme().colors().editor_background)
-            .flex()
-            .items_center()
-            .justify_center()
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if self.diff_stats.files_changed == 0 {
+            return div()
+                .bg(cx.theme().colors().editor_background)
+                .flex()
+                .items_center()
+                .justify_center()
+                .size_full()
+                .child(Label::new("No changes").color(Color::Muted));
+        }
+
+        h_flex()
             .size_full()
-            .child(self.editor.clone())
+            .bg(cx.theme().colors().editor_background)
+            .child(
+                v_flex()
+                    .id("project-diff-changes")
+                    .w_64()
+                    .h_full()
+                    .flex_none()
+                    .overflow_y_scroll()
+                    .border_r_1()
+                    .border_color(cx.theme().colors().border)
+                    .children(self.render_changes_list(cx)),
+            )
+            .child(div().flex_1().h_full().child(self.editor.clone()))
     }
 }
@@ -2,10 +2,20 @@ use crate::{DevicePixels, IsZero, Result, SharedString, Size, AnyAssetSource};
 use anyhow::anyhow;
 use std::hash::Hash;
 
+/// The pixel format an `SvgRenderer` should produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SvgRenderFormat {
+    /// A single-channel alpha mask, suitable for monochrome, tintable icons.
+    Alpha,
+    /// Premultiplied RGBA, preserving the SVG's own colors.
+    Rgba,
+}
+
 #[derive(Clone, PartialEq, Hash, Eq)]
 pub struct RenderSvgParams {
     pub(crate) path: SharedString,
     pub(crate) size: Size<DevicePixels>,
+    pub(crate) format: SvgRenderFormat,
 }
 
 pub struct SvgRenderer {
@@ -18,6 +28,23 @@ impl SvgRenderer {
     }
 
     pub fn render(&self, params: &RenderSvgParams) -> Result<Vec<u8>> {
+        let pixmap = self.render_pixmap(params)?;
+
+        match params.format {
+            // Convert the pixmap's pixels into an alpha mask.
+            SvgRenderFormat::Alpha => Ok(pixmap.pixels().iter().map(|p| p.alpha()).collect()),
+            SvgRenderFormat::Rgba => Ok(pixmap.data().to_vec()),
+        }
+    }
+
+    /// Renders the full, premultiplied RGBA pixels of the SVG, preserving
+    /// its original colors instead of collapsing it to an alpha mask.
+    pub fn render_rgba(&self, params: &RenderSvgParams) -> Result<Vec<u8>> {
+        let pixmap = self.render_pixmap(params)?;
+        Ok(pixmap.data().to_vec())
+    }
+
+    fn render_pixmap(&self, params: &RenderSvgParams) -> Result<tiny_skia::Pixmap> {
         if params.size.is_zero() {
             return Err(anyhow!("can't render at a zero size"));
         }
@@ -35,12 +62,6 @@ impl SvgRenderer {
             pixmap.as_mut(),
         );
 
-        // Convert the pixmap's pixels into an alpha mask.
-        let alpha_mask = pixmap
-            .pixels()
-            .iter()
-            .map(|p| p.alpha())
-            .collect::<Vec<_>>();
-        Ok(alpha_mask)
+        Ok(pixmap)
     }
 }